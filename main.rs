@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::io::{Read, Write};
 use std::net::{TcpListener, TcpStream};
@@ -19,15 +19,36 @@ struct ArticleHistory {
 }
 
 
-type WikiData = Arc<Mutex<HashMap<String, ArticleHistory>>>;
+#[derive(Clone, Debug, Default)]
+struct WikiState {
+    articles: HashMap<String, ArticleHistory>,
+    index: HashMap<String, HashMap<String, u32>>,
+    backlinks: HashMap<String, HashSet<String>>,
+}
+
+
+type WikiData = Arc<Mutex<WikiState>>;
+
+
+struct WikiConfig {
+    site_name: String,
+    front_page: String,
+    locked: HashSet<String>,
+}
+
+
+type WikiConfigRef = Arc<WikiConfig>;
+
+
+const RECENT_CHANGES_LIMIT: usize = 50;
 
 
 fn main() {
-    let wiki_data: WikiData = Arc::new(Mutex::new(HashMap::new()));
+    let wiki_data: WikiData = Arc::new(Mutex::new(WikiState::default()));
 
     {
-        let mut data = wiki_data.lock().unwrap();
-        data.insert("main".to_string(), ArticleHistory {
+        let mut state = wiki_data.lock().unwrap();
+        state.articles.insert("main".to_string(), ArticleHistory {
             versions: vec![Article {
                 title: "Main Page".to_string(),
                 content: "Welcome to BauhausWiki\n\nA minimalist encyclopedia inspired by Bauhaus design principles.\n\nFeatured Articles:\n- [[Bauhaus]]\n- [[Design]]\n- [[Architecture]]\n\nStart exploring or create a new article.".to_string(),
@@ -35,23 +56,37 @@ fn main() {
             }],
         });
 
-        data.insert("bauhaus".to_string(), ArticleHistory {
+        state.articles.insert("bauhaus".to_string(), ArticleHistory {
             versions: vec![Article {
                 title: "Bauhaus".to_string(),
                 content: "The Bauhaus\n\nThe Bauhaus was a German art school operational from 1919 to 1933 that combined crafts and the fine arts.\n\nKey Principles:\n- Form follows function\n- Unity of art and technology\n- Geometric abstraction\n- Primary colors and shapes\n\nThe Bauhaus style is characterized by geometric forms, clean lines, and a focus on functionality. It influenced [[Architecture]] and [[Design]] worldwide.".to_string(),
                 timestamp: timestamp(),
             }],
         });
+
+        update_index(&mut state, "main");
+        update_index(&mut state, "bauhaus");
+        update_backlinks(&mut state, "main");
+        update_backlinks(&mut state, "bauhaus");
     }
 
+    let mut locked = HashSet::new();
+    locked.insert("main".to_string());
+    let config: WikiConfigRef = Arc::new(WikiConfig {
+        site_name: "BauhausWiki".to_string(),
+        front_page: "main".to_string(),
+        locked,
+    });
+
     let listener = TcpListener::bind("0.0.0.0:24439").unwrap();
-    println!("BauhausWiki running at http://0.0.0.0:24439");
+    println!("{} running at http://0.0.0.0:24439", config.site_name);
 
     for stream in listener.incoming() {
         match stream {
             Ok(stream) => {
                 let wiki_clone = Arc::clone(&wiki_data);
-                std::thread::spawn(move || handle_client(stream, wiki_clone));
+                let config_clone = Arc::clone(&config);
+                std::thread::spawn(move || handle_client(stream, wiki_clone, config_clone));
             }
             Err(e) => eprintln!("Connection error: {}", e),
         }
@@ -59,12 +94,12 @@ fn main() {
 }
 
 
-fn handle_client(mut stream: TcpStream, wiki_data: WikiData) {
+fn handle_client(mut stream: TcpStream, wiki_data: WikiData, config: WikiConfigRef) {
     let mut buffer = [0; 4096];
     match stream.read(&mut buffer) {
         Ok(size) => {
             let request = String::from_utf8_lossy(&buffer[..size]);
-            let response = process_request(&request, wiki_data);
+            let response = process_request(&request, wiki_data, config);
             let _ = stream.write_all(response.as_bytes());
             let _ = stream.flush();
         }
@@ -73,7 +108,7 @@ fn handle_client(mut stream: TcpStream, wiki_data: WikiData) {
 }
 
 
-fn process_request(request: &str, wiki_data: WikiData) -> String {
+fn process_request(request: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
     let lines: Vec<&str> = request.lines().collect();
     if lines.is_empty() {
         return http_response(400, "Bad Request");
@@ -88,36 +123,52 @@ fn process_request(request: &str, wiki_data: WikiData) -> String {
     let path = parts[1];
 
     match method {
-        "GET" => handle_get(path, wiki_data),
-        "POST" => handle_post(path, request, wiki_data),
+        "GET" => handle_get(path, wiki_data, config),
+        "POST" => handle_post(path, request, wiki_data, config),
         _ => http_response(405, "Method Not Allowed"),
     }
 }
 
 
-fn handle_get(path: &str, wiki_data: WikiData) -> String {
+fn handle_get(path: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
     if path == "/" {
-        return handle_get("/wiki/main", wiki_data);
+        let front_page = format!("/wiki/{}", config.front_page);
+        return handle_get(&front_page, wiki_data, config);
     }
 
-    if path.starts_with("/wiki/") {
-        let article_name = &path[6..];
-        return view_article(article_name, wiki_data);
+    if let Some(article_name) = path.strip_prefix("/wiki/") {
+        return view_article(article_name, wiki_data, config);
     }
 
-    if path.starts_with("/edit/") {
-        let article_name = &path[6..];
-        return edit_page(article_name, wiki_data);
+    if let Some(article_name) = path.strip_prefix("/edit/") {
+        return edit_page(article_name, wiki_data, config);
     }
 
-    if path.starts_with("/history/") {
-        let article_name = &path[9..];
-        return history_page(article_name, wiki_data);
+    if let Some(article_name) = path.strip_prefix("/history/") {
+        return history_page(article_name, wiki_data, config);
+    }
+
+    if let Some(rest) = path.strip_prefix("/diff/") {
+        let article_name = match rest.find('?') {
+            Some(i) => &rest[..i],
+            None => rest,
+        };
+        let from = extract_query_param(path, "from").parse::<usize>().unwrap_or(0);
+        let to = extract_query_param(path, "to").parse::<usize>().unwrap_or(0);
+        return diff_page(article_name, from, to, wiki_data, config);
     }
 
     if path.starts_with("/search") {
         let query = extract_query_param(path, "q");
-        return search_page(&query, wiki_data);
+        return search_page(&query, wiki_data, config);
+    }
+
+    if path == "/recent" {
+        return recent_page(wiki_data, config);
+    }
+
+    if path == "/recent.xml" {
+        return recent_feed(wiki_data, config);
     }
 
     if path == "/styles.css" {
@@ -128,9 +179,11 @@ fn handle_get(path: &str, wiki_data: WikiData) -> String {
 }
 
 
-fn handle_post(path: &str, request: &str, wiki_data: WikiData) -> String {
-    if path.starts_with("/save/") {
-        let article_name = &path[6..];
+fn handle_post(path: &str, request: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
+    if let Some(article_name) = path.strip_prefix("/save/") {
+        if config.locked.contains(article_name) {
+            return http_response(403, "Forbidden: this page is locked");
+        }
         let body = extract_body(request);
         let content = extract_form_param(&body, "content");
         save_article(article_name, &content, wiki_data);
@@ -141,25 +194,29 @@ fn handle_post(path: &str, request: &str, wiki_data: WikiData) -> String {
 }
 
 
-fn view_article(name: &str, wiki_data: WikiData) -> String {
+fn view_article(name: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
     let data = wiki_data.lock().unwrap();
+    let sidebar = render_sidebar(&data.articles);
 
-    match data.get(name) {
+    match data.articles.get(name) {
         Some(history) => {
             let article = &history.versions.last().unwrap();
-            let html_content = markdown_to_html(&article.content);
+            let (html_content, outline) = markdown_to_html(&article.content);
+            let toc_html = render_toc(&outline);
+            let backlinks_html = render_backlinks(&data.backlinks, name);
             html_response(&format!(
                 r#"<!DOCTYPE html>
 <html>
 <head>
     <meta charset="UTF-8">
-    <title>{} - BauhausWiki</title>
+    <title>{} - {}</title>
     <link rel="stylesheet" href="/styles.css">
 </head>
 <body>
     <header>
-        <h1><a href="/">BauhausWiki</a></h1>
+        <h1><a href="/">{}</a></h1>
         <nav>
+            <a href="/recent" class="btn">Recent Changes</a>
             <form action="/search" method="get" class="search-form">
                 <input type="text" name="q" placeholder="Search..." class="search-input">
                 <button type="submit" class="primary-btn">Search</button>
@@ -167,75 +224,220 @@ fn view_article(name: &str, wiki_data: WikiData) -> String {
         </nav>
     </header>
     <main>
-        <article>
-            <div class="article-header">
-                <h2>{}</h2>
-                <div class="article-actions">
-                    <a href="/edit/{}" class="btn">Edit</a>
-                    <a href="/history/{}" class="btn">History</a>
+        <aside class="sidebar">
+            <h3>Wiki Outline</h3>
+            {}
+        </aside>
+        <div class="article-wrap">
+            <article>
+                <div class="article-header">
+                    <h2>{}</h2>
+                    <div class="article-actions">
+                        <a href="/edit/{}" class="btn">Edit</a>
+                        <a href="/history/{}" class="btn">History</a>
+                    </div>
                 </div>
-            </div>
-            <div class="article-content">
                 {}
-            </div>
-        </article>
+                <div class="article-content">
+                    {}
+                </div>
+                {}
+            </article>
+        </div>
     </main>
     <footer>
         <p>2025 OttoCompiler</p>
     </footer>
 </body>
 </html>"#,
-                article.title, article.title, name, name, html_content
+                article.title, config.site_name, config.site_name, sidebar, article.title, name, name, toc_html, html_content, backlinks_html
             ))
         }
         None => {
+            let backlinks_html = render_backlinks(&data.backlinks, name);
             html_response(&format!(
                 r#"<!DOCTYPE html>
 <html>
 <head>
     <meta charset="UTF-8">
-    <title>Article Not Found - BauhausWiki</title>
+    <title>Article Not Found - {}</title>
     <link rel="stylesheet" href="/styles.css">
 </head>
 <body>
     <header>
-        <h1><a href="/">BauhausWiki</a></h1>
+        <h1><a href="/">{}</a></h1>
     </header>
     <main>
-        <div class="not-found">
-            <h2>Article Not Found: {}</h2>
-            <p>This article does not exist yet.</p>
-            <a href="/edit/{}" class="primary-btn">Create Article</a>
-            <a href="/" class="btn">Back to Main Page</a>
+        <aside class="sidebar">
+            <h3>Wiki Outline</h3>
+            {}
+        </aside>
+        <div class="article-wrap">
+            <div class="not-found">
+                <h2>Article Not Found: {}</h2>
+                <p>This article does not exist yet.</p>
+                <a href="/edit/{}" class="primary-btn">Create Article</a>
+                <a href="/" class="btn">Back to Main Page</a>
+            </div>
+            {}
         </div>
     </main>
 </body>
 </html>"#,
-                name, name
+                config.site_name, config.site_name, sidebar, name, name, backlinks_html
             ))
         }
     }
 }
 
 
-fn edit_page(name: &str, wiki_data: WikiData) -> String {
+struct TreeNode {
+    link: Option<(String, String)>,
+    subs: HashMap<String, TreeNode>,
+}
+
+
+fn build_tree(data: &HashMap<String, ArticleHistory>) -> TreeNode {
+    let mut root = TreeNode { link: None, subs: HashMap::new() };
+
+    for (name, history) in data.iter() {
+        let title = history.versions.last().unwrap().title.clone();
+        let parts: Vec<&str> = name.split('/').collect();
+        let mut node = &mut root;
+        let mut path = String::new();
+
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                path.push('/');
+            }
+            path.push_str(part);
+            node = node.subs.entry(part.to_string())
+                .or_insert_with(|| TreeNode { link: None, subs: HashMap::new() });
+            if i == parts.len() - 1 {
+                node.link = Some((path.clone(), title.clone()));
+            }
+        }
+    }
+
+    root
+}
+
+
+fn render_tree(node: &TreeNode) -> String {
+    let mut keys: Vec<&String> = node.subs.keys().collect();
+    keys.sort();
+
+    let mut html = String::from("<ul>");
+    for key in keys {
+        let child = &node.subs[key];
+        html.push_str("<li>");
+        match &child.link {
+            Some((path, title)) => {
+                html.push_str(&format!(r#"<a href="/wiki/{}">{}</a>"#, path, escape_html(title)));
+            }
+            None => {
+                html.push_str(&format!(r#"<span class="tree-heading">{}</span>"#, escape_html(key)));
+            }
+        }
+        if !child.subs.is_empty() {
+            html.push_str(&render_tree(child));
+        }
+        html.push_str("</li>");
+    }
+    html.push_str("</ul>");
+    html
+}
+
+
+fn render_sidebar(data: &HashMap<String, ArticleHistory>) -> String {
+    let tree = build_tree(data);
+    render_tree(&tree)
+}
+
+
+fn render_backlinks(backlinks: &HashMap<String, HashSet<String>>, name: &str) -> String {
+    let mut sources: Vec<&String> = match backlinks.get(name) {
+        Some(sources) => sources.iter().collect(),
+        None => return String::new(),
+    };
+    sources.sort();
+
+    let items: String = sources.iter()
+        .map(|source| format!(r#"<li><a href="/wiki/{}">{}</a></li>"#, source, escape_html(source)))
+        .collect();
+
+    format!(
+        r#"<div class="backlinks-box"><h3>What links here</h3><ul>{}</ul></div>"#,
+        items
+    )
+}
+
+
+fn render_toc(outline: &[(String, String)]) -> String {
+    if outline.is_empty() {
+        return String::new();
+    }
+
+    let items: String = outline.iter()
+        .map(|(slug, heading)| format!(r##"<li><a href="#{}">{}</a></li>"##, slug, escape_html(heading)))
+        .collect();
+
+    format!(
+        r#"<div class="toc-box"><h3>Contents</h3><ul>{}</ul></div>"#,
+        items
+    )
+}
+
+
+fn edit_page(name: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
     let data = wiki_data.lock().unwrap();
-    let content = match data.get(name) {
+    let content = match data.articles.get(name) {
         Some(history) => history.versions.last().unwrap().content.clone(),
         None => String::new(),
     };
 
+    if config.locked.contains(name) {
+        return html_response(&format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>Edit {} - {}</title>
+    <link rel="stylesheet" href="/styles.css">
+</head>
+<body>
+    <header>
+        <h1><a href="/">{}</a></h1>
+    </header>
+    <main>
+        <article>
+            <h2>{}</h2>
+            <div class="locked-notice">
+                <p>This page is locked by the site operator and cannot be edited.</p>
+            </div>
+            <div class="article-content">
+                {}
+            </div>
+            <a href="/wiki/{}" class="btn">Back to Article</a>
+        </article>
+    </main>
+</body>
+</html>"#,
+            name, config.site_name, config.site_name, name, markdown_to_html(&content).0, name
+        ));
+    }
+
     html_response(&format!(
         r#"<!DOCTYPE html>
 <html>
 <head>
     <meta charset="UTF-8">
-    <title>Edit {} - BauhausWiki</title>
+    <title>Edit {} - {}</title>
     <link rel="stylesheet" href="/styles.css">
 </head>
 <body>
     <header>
-        <h1><a href="/">BauhausWiki</a></h1>
+        <h1><a href="/">{}</a></h1>
     </header>
     <main>
         <article>
@@ -259,28 +461,38 @@ fn edit_page(name: &str, wiki_data: WikiData) -> String {
     </main>
 </body>
 </html>"#,
-        name, name, name, escape_html(&content), name
+        name, config.site_name, config.site_name, name, name, escape_html(&content), name
     ))
 }
 
 
-fn history_page(name: &str, wiki_data: WikiData) -> String {
+fn history_page(name: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
     let data = wiki_data.lock().unwrap();
 
-    match data.get(name) {
+    match data.articles.get(name) {
         Some(history) => {
             let mut versions_html = String::new();
             for (i, version) in history.versions.iter().enumerate().rev() {
                 let date = format_timestamp(version.timestamp);
+                let compare_link = if i > 0 {
+                    format!(
+                        r#"<a href="/diff/{}?from={}&to={}" class="btn">Compare with previous</a>"#,
+                        name, i, i + 1
+                    )
+                } else {
+                    String::new()
+                };
                 versions_html.push_str(&format!(
                     r#"<div class="history-item">
                         <div class="history-number">Version {}</div>
                         <div class="history-date">{}</div>
                         <div class="history-preview">{}</div>
+                        {}
                     </div>"#,
                     i + 1,
                     date,
-                    escape_html(&version.content.chars().take(100).collect::<String>())
+                    escape_html(&version.content.chars().take(100).collect::<String>()),
+                    compare_link
                 ));
             }
 
@@ -289,12 +501,12 @@ fn history_page(name: &str, wiki_data: WikiData) -> String {
 <html>
 <head>
     <meta charset="UTF-8">
-    <title>History: {} - BauhausWiki</title>
+    <title>History: {} - {}</title>
     <link rel="stylesheet" href="/styles.css">
 </head>
 <body>
     <header>
-        <h1><a href="/">BauhausWiki</a></h1>
+        <h1><a href="/">{}</a></h1>
     </header>
     <main>
         <article>
@@ -307,7 +519,7 @@ fn history_page(name: &str, wiki_data: WikiData) -> String {
     </main>
 </body>
 </html>"#,
-                name, name, versions_html, name
+                name, config.site_name, config.site_name, name, versions_html, name
             ))
         }
         None => redirect_response(&format!("/wiki/{}", name)),
@@ -315,24 +527,169 @@ fn history_page(name: &str, wiki_data: WikiData) -> String {
 }
 
 
-fn search_page(query: &str, wiki_data: WikiData) -> String {
+fn diff_page(name: &str, from: usize, to: usize, wiki_data: WikiData, config: WikiConfigRef) -> String {
     let data = wiki_data.lock().unwrap();
-    let mut results = Vec::new();
 
-    let query_lower = query.to_lowercase();
-    for (name, history) in data.iter() {
-        let article = history.versions.last().unwrap();
-        if article.title.to_lowercase().contains(&query_lower)
-            || article.content.to_lowercase().contains(&query_lower) {
-            results.push((name.clone(), article.title.clone()));
+    match data.articles.get(name) {
+        Some(history) => {
+            let len = history.versions.len();
+            if from < 1 || to < 1 || from > len || to > len {
+                return html_response(&format!(
+                    r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>Diff: {} - {}</title>
+    <link rel="stylesheet" href="/styles.css">
+</head>
+<body>
+    <header>
+        <h1><a href="/">{}</a></h1>
+    </header>
+    <main>
+        <article>
+            <h2>Invalid revision range</h2>
+            <p>Requested versions do not exist for this article.</p>
+            <a href="/history/{}" class="btn">Back to History</a>
+        </article>
+    </main>
+</body>
+</html>"#,
+                    name, config.site_name, config.site_name, name
+                ));
+            }
+
+            let from_content = &history.versions[from - 1].content;
+            let to_content = &history.versions[to - 1].content;
+            let a_lines: Vec<&str> = from_content.lines().collect();
+            let b_lines: Vec<&str> = to_content.lines().collect();
+
+            let mut diff_html = String::new();
+            for (kind, line) in diff_lines(&a_lines, &b_lines) {
+                let class = match kind {
+                    DiffKind::Added => "diff-add",
+                    DiffKind::Removed => "diff-del",
+                    DiffKind::Unchanged => "diff-eq",
+                };
+                diff_html.push_str(&format!(
+                    r#"<div class="{}">{}</div>"#,
+                    class,
+                    escape_html(&line)
+                ));
+            }
+
+            html_response(&format!(
+                r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>Diff: {} - {}</title>
+    <link rel="stylesheet" href="/styles.css">
+</head>
+<body>
+    <header>
+        <h1><a href="/">{}</a></h1>
+    </header>
+    <main>
+        <article>
+            <h2>Comparing Version {} to Version {}: {}</h2>
+            <div class="diff-view">
+                {}
+            </div>
+            <a href="/history/{}" class="btn">Back to History</a>
+        </article>
+    </main>
+</body>
+</html>"#,
+                name, config.site_name, config.site_name, from, to, name, diff_html, name
+            ))
+        }
+        None => redirect_response(&format!("/wiki/{}", name)),
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum DiffKind {
+    Added,
+    Removed,
+    Unchanged,
+}
+
+
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<(DiffKind, String)> {
+    let n = a.len();
+    let m = b.len();
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if a[i] == b[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
         }
     }
 
-    let results_html = if results.is_empty() {
+    let mut result = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+    while i < n && j < m {
+        if a[i] == b[j] {
+            result.push((DiffKind::Unchanged, a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            result.push((DiffKind::Removed, a[i].to_string()));
+            i += 1;
+        } else {
+            result.push((DiffKind::Added, b[j].to_string()));
+            j += 1;
+        }
+    }
+    while i < n {
+        result.push((DiffKind::Removed, a[i].to_string()));
+        i += 1;
+    }
+    while j < m {
+        result.push((DiffKind::Added, b[j].to_string()));
+        j += 1;
+    }
+    result
+}
+
+
+fn search_page(query: &str, wiki_data: WikiData, config: WikiConfigRef) -> String {
+    let data = wiki_data.lock().unwrap();
+    let terms = tokenize(query);
+    let article_count = data.articles.len() as f64;
+
+    let mut scores: HashMap<String, f64> = HashMap::new();
+    for term in &terms {
+        if let Some(postings) = data.index.get(term) {
+            let df = postings.len() as f64;
+            let idf = (article_count / df).ln().max(0.0);
+            for (name, &tf) in postings {
+                *scores.entry(name.clone()).or_insert(0.0) += tf as f64 * idf;
+            }
+        }
+    }
+
+    let mut ranked: Vec<(&String, f64)> = scores.iter().map(|(name, score)| (name, *score)).collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let results_html = if ranked.is_empty() {
         "<p>No articles found.</p>".to_string()
     } else {
-        results.iter()
-            .map(|(name, title)| format!(r#"<div class="search-result"><a href="/wiki/{}">{}</a></div>"#, name, title))
+        ranked.iter()
+            .map(|(name, _)| {
+                let article = data.articles[*name].versions.last().unwrap();
+                let snippet = search_snippet(&article.content, &terms);
+                format!(
+                    r#"<div class="search-result"><a href="/wiki/{}">{}</a><p class="search-snippet">{}</p></div>"#,
+                    name, article.title, snippet
+                )
+            })
             .collect::<Vec<_>>()
             .join("\n")
     };
@@ -342,12 +699,12 @@ fn search_page(query: &str, wiki_data: WikiData) -> String {
 <html>
 <head>
     <meta charset="UTF-8">
-    <title>Search: {} - BauhausWiki</title>
+    <title>Search: {} - {}</title>
     <link rel="stylesheet" href="/styles.css">
 </head>
 <body>
     <header>
-        <h1><a href="/">BauhausWiki</a></h1>
+        <h1><a href="/">{}</a></h1>
     </header>
     <main>
         <article>
@@ -360,11 +717,123 @@ fn search_page(query: &str, wiki_data: WikiData) -> String {
     </main>
 </body>
 </html>"#,
-        query, escape_html(query), results_html
+        query, config.site_name, config.site_name, escape_html(query), results_html
+    ))
+}
+
+
+fn collect_recent_changes(articles: &HashMap<String, ArticleHistory>, limit: usize) -> Vec<(String, usize, Article)> {
+    let mut changes: Vec<(String, usize, Article)> = Vec::new();
+    for (name, history) in articles.iter() {
+        for (i, version) in history.versions.iter().enumerate() {
+            changes.push((name.clone(), i + 1, version.clone()));
+        }
+    }
+    changes.sort_by_key(|c| std::cmp::Reverse(c.2.timestamp));
+    changes.truncate(limit);
+    changes
+}
+
+
+fn recent_page(wiki_data: WikiData, config: WikiConfigRef) -> String {
+    let data = wiki_data.lock().unwrap();
+    let changes = collect_recent_changes(&data.articles, RECENT_CHANGES_LIMIT);
+
+    let mut changes_html = String::new();
+    for (name, version, article) in &changes {
+        let date = format_timestamp(article.timestamp);
+        let diff_link = if *version > 1 {
+            format!(
+                r#"<a href="/diff/{}?from={}&to={}" class="btn">Diff</a>"#,
+                name, version - 1, version
+            )
+        } else {
+            String::new()
+        };
+        changes_html.push_str(&format!(
+            r#"<div class="history-item">
+                <div class="history-number"><a href="/wiki/{}">{}</a> &mdash; Version {}</div>
+                <div class="history-date">{}</div>
+                {}
+            </div>"#,
+            name, escape_html(&article.title), version, date, diff_link
+        ));
+    }
+
+    html_response(&format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="UTF-8">
+    <title>Recent Changes - {}</title>
+    <link rel="stylesheet" href="/styles.css">
+</head>
+<body>
+    <header>
+        <h1><a href="/">{}</a></h1>
+    </header>
+    <main>
+        <article>
+            <div class="article-header">
+                <h2>Recent Changes</h2>
+                <div class="article-actions">
+                    <a href="/recent.xml" class="btn">Atom Feed</a>
+                </div>
+            </div>
+            <div class="history-list">
+                {}
+            </div>
+            <a href="/" class="btn">Back to Main Page</a>
+        </article>
+    </main>
+</body>
+</html>"#,
+        config.site_name, config.site_name, changes_html
     ))
 }
 
 
+fn recent_feed(wiki_data: WikiData, config: WikiConfigRef) -> String {
+    let data = wiki_data.lock().unwrap();
+    let changes = collect_recent_changes(&data.articles, RECENT_CHANGES_LIMIT);
+
+    let latest_updated = changes.first()
+        .map(|(_, _, article)| atom_timestamp(article.timestamp))
+        .unwrap_or_else(|| atom_timestamp(0));
+
+    let mut entries = String::new();
+    for (name, version, article) in &changes {
+        entries.push_str(&format!(
+            r#"  <entry>
+    <title>{}</title>
+    <id>tag:bauhauswiki,{}#v{}</id>
+    <updated>{}</updated>
+    <link href="/wiki/{}"/>
+    <summary>Version {} of {}</summary>
+  </entry>
+"#,
+            escape_html(&article.title), escape_html(name), version, atom_timestamp(article.timestamp),
+            escape_html(name), version, escape_html(&article.title)
+        ));
+    }
+
+    xml_response(&format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>{} Recent Changes</title>
+  <id>tag:bauhauswiki,recent</id>
+  <updated>{}</updated>
+{}</feed>"#,
+        escape_html(&config.site_name), latest_updated, entries
+    ))
+}
+
+
+fn atom_timestamp(ts: u64) -> String {
+    format!("{}T00:00:00Z", format_timestamp(ts))
+}
+
+
 fn save_article(name: &str, content: &str, wiki_data: WikiData) {
     let mut data = wiki_data.lock().unwrap();
     let title = name.replace("_", " ").split_whitespace()
@@ -384,15 +853,136 @@ fn save_article(name: &str, content: &str, wiki_data: WikiData) {
         timestamp: timestamp(),
     };
 
-    data.entry(name.to_string())
+    data.articles.entry(name.to_string())
         .or_insert_with(|| ArticleHistory { versions: Vec::new() })
         .versions
         .push(article);
+
+    update_index(&mut data, name);
+    update_backlinks(&mut data, name);
+}
+
+
+fn update_backlinks(state: &mut WikiState, name: &str) {
+    for sources in state.backlinks.values_mut() {
+        sources.remove(name);
+    }
+    state.backlinks.retain(|_, sources| !sources.is_empty());
+
+    let history = match state.articles.get(name) {
+        Some(history) => history,
+        None => return,
+    };
+    let content = &history.versions.last().unwrap().content;
+
+    for target in extract_link_targets(content) {
+        state.backlinks.entry(target)
+            .or_default()
+            .insert(name.to_string());
+    }
+}
+
+
+fn extract_link_targets(content: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '[' && chars.peek() == Some(&'[') {
+            chars.next();
+            let mut link = String::new();
+            while let Some(c) = chars.next() {
+                if c == ']' && chars.peek() == Some(&']') {
+                    chars.next();
+                    targets.push(link.to_lowercase().replace(" ", "_"));
+                    break;
+                }
+                link.push(c);
+            }
+        }
+    }
+
+    targets
+}
+
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+
+fn update_index(state: &mut WikiState, name: &str) {
+    for postings in state.index.values_mut() {
+        postings.remove(name);
+    }
+    state.index.retain(|_, postings| !postings.is_empty());
+
+    let history = match state.articles.get(name) {
+        Some(history) => history,
+        None => return,
+    };
+    let article = history.versions.last().unwrap();
+
+    let mut term_frequencies: HashMap<String, u32> = HashMap::new();
+    for term in tokenize(&article.title) {
+        *term_frequencies.entry(term).or_insert(0) += 1;
+    }
+    for term in tokenize(&article.content) {
+        *term_frequencies.entry(term).or_insert(0) += 1;
+    }
+
+    for (term, frequency) in term_frequencies {
+        state.index.entry(term)
+            .or_default()
+            .insert(name.to_string(), frequency);
+    }
+}
+
+
+fn search_snippet(content: &str, terms: &[String]) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let lower_chars: Vec<char> = content.to_lowercase().chars().collect();
+
+    let mut best: Option<(usize, usize)> = None;
+    for term in terms {
+        let term_chars: Vec<char> = term.chars().collect();
+        if term_chars.is_empty() || term_chars.len() > lower_chars.len() {
+            continue;
+        }
+        for start in 0..=(lower_chars.len() - term_chars.len()) {
+            if lower_chars[start..start + term_chars.len()] == term_chars[..] {
+                if best.is_none_or(|(b, _)| start < b) {
+                    best = Some((start, term_chars.len()));
+                }
+                break;
+            }
+        }
+    }
+
+    match best {
+        Some((pos, len)) => {
+            let start = pos.saturating_sub(40);
+            let end = (pos + len + 40).min(chars.len());
+            let before: String = chars[start..pos].iter().collect();
+            let matched: String = chars[pos..pos + len].iter().collect();
+            let after: String = chars[pos + len..end].iter().collect();
+            format!(
+                "...{}<mark>{}</mark>{}...",
+                escape_html(&before), escape_html(&matched), escape_html(&after)
+            )
+        }
+        None => escape_html(&chars.iter().take(100).collect::<String>()),
+    }
 }
 
 
-fn markdown_to_html(content: &str) -> String {
+fn markdown_to_html(content: &str) -> (String, Vec<(String, String)>) {
     let mut html = String::new();
+    let mut outline: Vec<(String, String)> = Vec::new();
+    let mut seen_slugs: HashSet<String> = HashSet::new();
     let mut in_paragraph = false;
 
     for line in content.lines() {
@@ -408,7 +998,11 @@ fn markdown_to_html(content: &str) -> String {
 
         if trimmed.starts_with("# ") {
             if in_paragraph { html.push_str("</p>\n"); in_paragraph = false; }
-            html.push_str(&format!("<h2>{}</h2>\n", escape_html(&trimmed[2..])));
+            let heading = &trimmed[2..];
+            let slug = unique_slug(&mut seen_slugs, &slugify(heading));
+            html.push_str(&format!(r#"<h2 id="{}">{}</h2>"#, slug, escape_html(heading)));
+            html.push('\n');
+            outline.push((slug, heading.to_string()));
         } else if trimmed.starts_with("- ") {
             if in_paragraph { html.push_str("</p>\n"); in_paragraph = false; }
             html.push_str(&format!("<ul><li>{}</li></ul>\n", process_links(&trimmed[2..])));
@@ -427,7 +1021,35 @@ fn markdown_to_html(content: &str) -> String {
         html.push_str("</p>\n");
     }
 
-    html
+    (html, outline)
+}
+
+
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    let mut prev_dash = false;
+    for c in text.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+            prev_dash = false;
+        } else if !prev_dash {
+            slug.push('-');
+            prev_dash = true;
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+
+fn unique_slug(seen: &mut HashSet<String>, base: &str) -> String {
+    let mut count = 1;
+    let mut candidate = base.to_string();
+    while seen.contains(&candidate) {
+        count += 1;
+        candidate = format!("{}-{}", base, count);
+    }
+    seen.insert(candidate.clone());
+    candidate
 }
 
 
@@ -514,9 +1136,51 @@ nav {
 }
 
 main {
-    max-width: 900px;
+    max-width: 1100px;
     margin: 2rem auto;
     padding: 0 2rem;
+    display: flex;
+    gap: 2rem;
+    align-items: flex-start;
+}
+
+.sidebar {
+    flex: 0 0 220px;
+    border: 2px solid #000000;
+    background: #f5f5f5;
+    padding: 1rem;
+}
+
+.sidebar h3 {
+    margin-bottom: 0.75rem;
+    font-size: 1.1rem;
+}
+
+.sidebar ul {
+    list-style: none;
+    margin-left: 1rem;
+}
+
+.sidebar li {
+    margin: 0.25rem 0;
+}
+
+.sidebar a {
+    color: #0000ff;
+    text-decoration: none;
+}
+
+.sidebar a:hover {
+    text-decoration: underline;
+}
+
+.tree-heading {
+    font-weight: 700;
+}
+
+.article-wrap {
+    flex: 1;
+    min-width: 0;
 }
 
 article {
@@ -544,6 +1208,31 @@ article {
     gap: 0.5rem;
 }
 
+.toc-box {
+    background: #f5f5f5;
+    border: 2px solid #000000;
+    padding: 1rem;
+    margin-bottom: 1.5rem;
+}
+
+.toc-box h3 {
+    margin-bottom: 0.5rem;
+    font-size: 1.1rem;
+}
+
+.toc-box ul {
+    list-style-position: inside;
+}
+
+.toc-box a {
+    color: #0000ff;
+    text-decoration: none;
+}
+
+.toc-box a:hover {
+    text-decoration: underline;
+}
+
 .article-content {
     font-size: 1.1rem;
 }
@@ -620,6 +1309,14 @@ article {
     gap: 0.5rem;
 }
 
+.locked-notice {
+    background: #fff3cd;
+    border: 2px solid #000000;
+    padding: 1rem;
+    margin-bottom: 1.5rem;
+    font-weight: 600;
+}
+
 .help-box {
     background: #f5f5f5;
     border: 2px solid #000000;
@@ -636,6 +1333,31 @@ article {
     list-style-position: inside;
 }
 
+.backlinks-box {
+    background: #f5f5f5;
+    border: 2px solid #000000;
+    padding: 1rem;
+    margin-top: 2rem;
+}
+
+.backlinks-box h3 {
+    margin-bottom: 0.5rem;
+    font-size: 1.1rem;
+}
+
+.backlinks-box ul {
+    list-style-position: inside;
+}
+
+.backlinks-box a {
+    color: #0000ff;
+    text-decoration: none;
+}
+
+.backlinks-box a:hover {
+    text-decoration: underline;
+}
+
 .not-found {
     text-align: center;
     padding: 3rem 0;
@@ -678,6 +1400,34 @@ article {
     font-size: 0.9rem;
 }
 
+.diff-view {
+    margin: 2rem 0;
+    font-family: 'Courier New', monospace;
+    font-size: 0.95rem;
+    border: 2px solid #000000;
+}
+
+.diff-view div {
+    padding: 0.25rem 0.75rem;
+    white-space: pre-wrap;
+}
+
+.diff-add {
+    background: #e6ffe6;
+    color: #006600;
+}
+
+.diff-del {
+    background: #ffe6e6;
+    color: #990000;
+    text-decoration: line-through;
+}
+
+.diff-eq {
+    background: #ffffff;
+    color: #000000;
+}
+
 .search-results {
     margin: 2rem 0;
 }
@@ -700,6 +1450,16 @@ article {
     text-decoration: underline;
 }
 
+.search-snippet {
+    margin-top: 0.5rem;
+    color: #333333;
+}
+
+.search-snippet mark {
+    background: #ffff00;
+    font-weight: 700;
+}
+
 footer {
     text-align: center;
     padding: 2rem;
@@ -726,6 +1486,14 @@ fn html_response(body: &str) -> String {
 }
 
 
+fn xml_response(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/xml; charset=UTF-8\r\n\r\n{}",
+        body
+    )
+}
+
+
 fn redirect_response(location: &str) -> String {
     format!(
         "HTTP/1.1 303 See Other\r\nLocation: {}\r\n\r\n",